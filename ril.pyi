@@ -70,6 +70,37 @@ class Image:
             A List of pixels.
         """
 
+    @classmethod
+    def gradient_mask(cls: Type[Image], width: int, height: int, direction: str) -> Image:
+        """
+        Creates a new `L` image containing a smooth gradient ramp from 0 to 255.
+        
+        This is useful for generating masks to be used with :meth:`mask_alpha`, such as for fading out the edges of an image.
+        
+        Parameters
+        ----------
+        width: int
+            The width of the mask.
+        height: int
+            The height of the mask.
+        direction: str
+            The direction of the ramp. `horizontal` ramps from left to right, `vertical` ramps from top to bottom,
+            and `radial` ramps from the corners towards the center.
+        
+        Raises
+        ------
+        ValueError
+            The direction is not one of `horizontal`, `vertical`, or `radial`
+        
+        Examples
+        --------
+        
+        .. code-block:: python3
+        
+            mask = Image.gradient_mask(image.width, image.height, "horizontal")
+            image.mask_alpha(mask)
+        """
+
     @classmethod
     def open(cls: Type[Image], path: str) -> Image:
         """
@@ -158,7 +189,59 @@ class Image:
             The entity to draw on the image.
         """
 
-    def resize(self, width: int, height: int, algorithm: ResizeAlgorithm) -> None:
+    def draw_path(
+        self,
+        path: str,
+        fill: Optional[Pixel] = None,
+        stroke: Optional[Pixel] = None,
+        thickness: int = 1,
+        cap: str = "butt",
+        join: str = "miter",
+    ) -> None:
+        """
+        Draws the given SVG-like path string onto this image.
+        
+        The supported commands are moveto (`M`), lineto (`L`, `H`, `V`), cubic and quadratic curveto (`C`, `Q`),
+        and closepath (`Z`), in both their absolute (uppercase) and relative (lowercase) forms.
+        
+        The fill and stroke are overlaid onto this image following its :attr:`overlay_mode`, like the other draw entities.
+        
+        Parameters
+        ----------
+        path: str
+            The path data, for example `M10 10 L90 10 L90 90 Z`
+        fill: Optional[:class:`.Pixel`], default: None
+            The color to fill the path with. Open subpaths are implicitly closed when filling.
+        stroke: Optional[:class:`.Pixel`], default: None
+            The color to stroke the outline of the path with.
+        thickness: int, default: 1
+            The thickness of the stroke, in pixels.
+        cap: str, default: "butt"
+            The shape drawn at the ends of open subpaths, one of `butt`, `round`, or `square`.
+        join: str, default: "miter"
+            The shape drawn at the corners of the stroke, one of `miter`, `round`, or `bevel`.
+            Sharp miters are drawn as bevels instead to avoid long spikes.
+        
+        Raises
+        ------
+        ValueError
+            The path data is invalid or contains an unsupported command, or the cap or join is invalid
+        
+        Examples
+        --------
+        
+        .. code-block:: python3
+        
+            image.draw_path("M10 10 L90 10 L90 90 Z", fill=Pixel.from_rgb(255, 0, 0))
+        """
+
+    def resize(
+        self,
+        width: int,
+        height: int,
+        algorithm: ResizeAlgorithm,
+        premultiply_alpha: bool = True,
+    ) -> None:
         """
         Resizes this image in place to the given dimensions using the given resizing algorithm in place.
         
@@ -170,6 +253,27 @@ class Image:
             The target height to resize to
         algorithm: :class:`.ResizeAlgorithm`
             The resize algorithm to use
+        premultiply_alpha: bool, default: True
+            Whether to premultiply the color channels by the alpha channel while resampling `RGBA` images.
+            This prevents the colors of fully transparent pixels from bleeding into their neighbours,
+            which otherwise causes dark fringes around transparent edges. Ignored for other modes,
+            and for :attr:`.ResizeAlgorithm.Nearest` since it never blends pixels.
+        """
+
+    def mipmaps(self) -> List[Image]:
+        """
+        Generates the mipmap chain of this image, which is a list of progressively halved versions of it down to 1x1.
+        
+        The first level is a copy of this image, and each following level is downscaled from the previous level
+        using a box filter to avoid aliasing. Odd dimensions are rounded down, and dimensions never go below 1.
+        
+        `RGBA` images are premultiplied once and downscaled in premultiplied space throughout the chain,
+        so rounding errors do not accumulate from level to level.
+        
+        Returns
+        -------
+        List[:class:`Image`]
+            The levels of the mipmap chain, from largest to smallest.
         """
 
     def encode(self, encoding: str) -> bytes:
@@ -260,6 +364,46 @@ class Image:
             The mask provided is not of mode `BitPixel`
         """
 
+    def paste_tiled(
+        self,
+        x1: int,
+        y1: int,
+        x2: int,
+        y2: int,
+        pattern: Image,
+        mask: Optional[Image] = None,
+    ) -> None:
+        """
+        Fills the given bounding box of this image by repeatedly pasting the given pattern image, tiling it from the upper-left corner.
+        
+        Tiles that would extend past the region, or past the edges of this image, are clipped.
+        
+        If `mask` is provided, each tile will be masked with it. The mask must have the same dimensions as the pattern,
+        and only BitPixel images are currently supported for the masking image.
+        
+        Parameters
+        ----------
+        x1: int
+           The x axis of the upper-left corner
+        y1: int
+            The y axis of the upper-left corner
+        x2: int
+            The x axis of the lower-right corner
+        y2: int
+            The y axis of the lower-right corner
+        pattern: :class:`Image`
+            The image to tile.
+        mask: Optional[:class:`Image`], default: None
+            The mask to use for each tile, defaults to `None`
+        
+        Raises
+        ------
+        TypeError
+            The mask provided is not of mode `BitPixel`
+        ValueError
+            The mask does not have the same dimensions as the pattern
+        """
+
     def mask_alpha(self, mask: Image) -> None:
         """
         Masks the alpha values of this image with the luminance values of the given single-channel L image.
@@ -279,6 +423,224 @@ class Image:
             The mask provided is not of mode `L`
         """
 
+    def feather(self, radius: int) -> None:
+        """
+        Feathers, or softens the edges of this image in place by fading out its alpha values over `radius` pixels.
+        
+        Both the borders of the image and the boundaries of any existing transparent areas are feathered,
+        which makes cutouts blend in more naturally when pasted onto another image.
+        
+        Parameters
+        ----------
+        radius: int
+            The distance in pixels over which the alpha values fade out.
+        
+        Raises
+        ------
+        TypeError
+            The image is not of mode `RGBA`
+        """
+
+    def inpaint(self, mask: Image) -> None:
+        """
+        Fills the region marked by the given mask in place by propagating the surrounding pixels into it.
+        
+        This can be used to remove unwanted objects from an image, or to fill holes left by transparency.
+        Works best on small or thin regions, as larger regions will be filled with a smooth blur of their surroundings.
+        
+        The mask must have the same dimensions as this image. Pixels that are on (or non-zero for `L` masks) will be filled.
+        
+        Parameters
+        ----------
+        mask: :class:`Image`
+            The mask marking the pixels to fill
+        
+        Raises
+        ------
+        TypeError
+            The mask provided is not of mode `bitpixel` or `L`
+        ValueError
+            The mask does not have the same dimensions as this image
+        """
+
+    def chroma_key(
+        self,
+        key_color: Pixel,
+        tolerance: float,
+        spill_suppression: float = 0.0,
+        softness: float = 0.0,
+    ) -> None:
+        """
+        Chroma keys this image in place, making pixels close to `key_color` transparent. This converts the image to `RGBA`.
+        
+        The similarity of two colors is measured by their distance in RGB space, scaled to be between 0 and 255.
+        
+        By default the cutoff is hard, so pixels are either kept as is or made fully transparent. If `softness` is provided,
+        pixels whose distance is within `softness` past the tolerance are made partially transparent instead, which
+        smooths the edges of the keyed out area.
+        
+        If `spill_suppression` is provided, the tint of the key color that spills onto the remaining pixels, such as the
+        green fringes around a subject shot in front of a green screen, will be reduced. This only applies to key colors
+        with a single dominant channel, so it has no effect when keying out grays.
+        
+        Parameters
+        ----------
+        key_color: :class:`.Pixel`
+            The color to key out.
+        tolerance: float
+            The maximum distance, between 0 and 255, from the key color for a pixel to be made transparent.
+        spill_suppression: float, default: 0.0
+            How strongly to suppress color spill, between 0 (not at all) and 1 (completely).
+        softness: float, default: 0.0
+            The width of the band past the tolerance over which pixels fade from transparent to opaque.
+        """
+
+    def on_checkerboard(self, cell_size: int = 8) -> Image:
+        """
+        Composites this image over a gray checkerboard, like the transparency backdrop found in image editors.
+        
+        This is useful for previewing transparent images, as the returned image is always opaque.
+        
+        Parameters
+        ----------
+        cell_size: int, default: 8
+            The width and height of each cell of the checkerboard, in pixels.
+        
+        Returns
+        -------
+        :class:`Image`
+            A new `RGB` image of this image composited over the checkerboard.
+        """
+
+    def find_contours(
+        self,
+        holes: bool = False,
+    ) -> Tuple[List[List[Tuple[int, int]]], List[Tuple[int, List[Tuple[int, int]]]]]:
+        """
+        Finds the contours, or boundaries, of each connected region in this mask image.
+        
+        Pixels that are on (or non-zero for `L` masks) are considered part of a region, and diagonally adjacent pixels are
+        considered connected. The outer boundary of each region is traced clockwise, starting from the upper-left-most pixel of the region.
+        
+        Holes are regions of pixels that are off and fully enclosed by a region. The boundary of a hole is made of the pixels of
+        the enclosing region that border it, and is traced counter-clockwise, starting from the pixel to the left of the
+        upper-left-most pixel of the hole.
+        
+        Parameters
+        ----------
+        holes: bool, default: False
+            Whether to also find the boundaries of holes.
+        
+        Returns
+        -------
+        Tuple[List[List[Tuple[int, int]]], List[Tuple[int, List[Tuple[int, int]]]]]
+            The coordinates of the boundary pixels of each region, along with the index of the enclosing region's boundary
+            and the coordinates of the boundary pixels of each hole. The list of holes is empty if `holes` is False.
+        
+        Raises
+        ------
+        TypeError
+            The image is not of mode `bitpixel` or `L`
+        """
+
+    def connected_components(
+        self,
+        connectivity: int = 4,
+    ) -> Tuple[List[List[int]], int, List[Tuple[int, int, int, int, int]]]:
+        """
+        Labels each connected region in this mask image with a unique id.
+        
+        Pixels that are on (or non-zero for `L` masks) are considered part of a region. Regions are numbered from 1 in the
+        order they are first encountered when scanning the image row by row, and pixels that are not part of any region are labeled 0.
+        
+        Parameters
+        ----------
+        connectivity: int, default: 4
+            Either 4, to only consider horizontally and vertically adjacent pixels connected, or 8, to also consider
+            diagonally adjacent pixels connected.
+        
+        Returns
+        -------
+        Tuple[List[List[int]], int, List[Tuple[int, int, int, int, int]]]
+            A 2D list of the label of each pixel where each list is a row, the amount of regions, and the bounding box
+            `(x1, y1, x2, y2)` and area of each region, in label order. The lower-right corner of each bounding box is exclusive.
+        
+        Raises
+        ------
+        TypeError
+            The image is not of mode `bitpixel` or `L`
+        ValueError
+            The connectivity is not 4 or 8
+        """
+
+    def distance_transform(self) -> Image:
+        """
+        Computes the distance transform of this mask image, where each pixel that is on (or non-zero for `L` masks)
+        is set to its distance in pixels to the nearest pixel that is off.
+        
+        Distances are approximated using a chamfer distance transform, and are rounded and capped at 255.
+        Pixels that are off are set to 0, and if every pixel is on, every pixel is set to 255.
+        
+        Returns
+        -------
+        :class:`Image`
+            A new `L` image containing the distance of each pixel.
+        
+        Raises
+        ------
+        TypeError
+            The image is not of mode `bitpixel` or `L`
+        """
+
+    def match_template(self, template: Image) -> Tuple[int, int, float]:
+        """
+        Finds where the given template image best matches this image, such as for locating an icon within a screenshot.
+        
+        Both images are compared in grayscale, and each position is scored by the normalized cross-correlation
+        of the template with the region of this image it covers.
+        
+        Correlation is undefined for flat regions, so a template of a single solid color only matches regions of the exact same
+        luminance, which score 1, while every other region scores 0. Flat regions likewise score 0 against other templates.
+        
+        .. warning:: **This function involves heavy operation**
+        
+            Every position of the template is compared pixel by pixel, so this is a heavy operation for larger images and templates.
+            Consider downscaling both images beforehand.
+        
+        Parameters
+        ----------
+        template: :class:`Image`
+            The image to search for.
+        
+        Returns
+        -------
+        Tuple[int, int, float]
+            The x and y axis of the upper-left corner of the best match, and its score between -1 and 1,
+            where 1 is a perfect match.
+        
+        Raises
+        ------
+        ValueError
+            The template is larger than this image
+        """
+
+    def glow(self, threshold: int, intensity: float, radius: int) -> None:
+        """
+        Applies a bloom, or glow effect to this image in place.
+        
+        The areas of this image that are brighter than `threshold` are extracted, blurred by `radius`,
+        and then added back onto the image. The alpha channel is left untouched.
+        
+        Parameters
+        ----------
+        threshold: int
+            The minimum luminance, between 0 and 255, for a pixel to glow.
+        intensity: float
+            How strongly the glow is added back onto the image, where 1 adds it at full strength.
+        radius: int
+            The radius of the blur applied to the bright areas, in pixels.
+        """
+
     def mirror(self) -> None:
         """Mirrors, or flips this image horizontally (about the y-axis) in place."""
 
@@ -2,9 +2,11 @@ use std::path::PathBuf;
 
 use crate::draw::DrawEntity;
 use crate::error::Error;
+use crate::ops;
+use crate::path;
 use crate::pixels::{BitPixel, Pixel, Rgb, Rgba, L};
 use crate::types::{ResizeAlgorithm, OverlayMode};
-use crate::utils::cast_pixel_to_pyobject;
+use crate::utils::{cast_pixel_to_pyobject, cast_pixel_to_rgba};
 use pyo3::types::PyBytes;
 use pyo3::{
     exceptions::{PyTypeError, PyValueError},
@@ -22,6 +24,28 @@ pub struct Image {
     pub inner: RilImage<Dynamic>,
 }
 
+#[derive(Clone, Copy)]
+enum GradientDirection {
+    Horizontal,
+    Vertical,
+    Radial,
+}
+
+fn get_gradient_direction(direction: &str) -> PyResult<GradientDirection> {
+    match direction {
+        "horizontal" => Ok(GradientDirection::Horizontal),
+        "vertical" => Ok(GradientDirection::Vertical),
+        "radial" => Ok(GradientDirection::Radial),
+        _ => Err(PyValueError::new_err(
+            "direction provided is not valid, it must be one of `horizontal`, `vertical`, or `radial`"
+                .to_string(),
+        )),
+    }
+}
+
+/// How much higher than the other channels a channel of a chroma key color must be to be considered its dominant channel.
+const DOMINANT_CHANNEL_GAP: f32 = 16.;
+
 macro_rules! cast_bands_to_pyobjects {
     ($py:expr, $($band:expr),*) => {{
         Ok((
@@ -135,6 +159,62 @@ impl Image {
         }
     }
 
+    /// Creates a new `L` image containing a smooth gradient ramp from 0 to 255.
+    ///
+    /// This is useful for generating masks to be used with :meth:`mask_alpha`, such as for fading out the edges of an image.
+    ///
+    /// Parameters
+    /// ----------
+    /// width: int
+    ///     The width of the mask.
+    /// height: int
+    ///     The height of the mask.
+    /// direction: str
+    ///     The direction of the ramp. `horizontal` ramps from left to right, `vertical` ramps from top to bottom,
+    ///     and `radial` ramps from the corners towards the center.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The direction is not one of `horizontal`, `vertical`, or `radial`
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// .. code-block:: python3
+    ///
+    ///     mask = Image.gradient_mask(image.width, image.height, "horizontal")
+    ///     image.mask_alpha(mask)
+    #[classmethod]
+    #[pyo3(text_signature = "(cls, width, height, direction)")]
+    fn gradient_mask(_: &PyType, width: u32, height: u32, direction: &str) -> PyResult<Self> {
+        let direction = get_gradient_direction(direction)?;
+
+        let max_x = width.saturating_sub(1).max(1) as f64;
+        let max_y = height.saturating_sub(1).max(1) as f64;
+        let (cx, cy) = (max_x / 2., max_y / 2.);
+        let max_distance = cx.hypot(cy);
+
+        let mut pixels = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let t = match direction {
+                    GradientDirection::Horizontal => x as f64 / max_x,
+                    GradientDirection::Vertical => y as f64 / max_y,
+                    GradientDirection::Radial => {
+                        1. - (x as f64 - cx).hypot(y as f64 - cy) / max_distance
+                    }
+                };
+
+                pixels.push(ril::L((t.clamp(0., 1.) * 255.).round() as u8));
+            }
+        }
+
+        Ok(Self::from_inner(
+            RilImage::from_pixels(width, pixels).convert::<Dynamic>(),
+        ))
+    }
+
     /// Opens a file from the given path and decodes it into an image.
     ///
     /// The encoding of the image is automatically inferred.
@@ -290,6 +370,74 @@ impl Image {
         Ok(())
     }
 
+    /// Draws the given SVG-like path string onto this image.
+    ///
+    /// The supported commands are moveto (`M`), lineto (`L`, `H`, `V`), cubic and quadratic curveto (`C`, `Q`),
+    /// and closepath (`Z`), in both their absolute (uppercase) and relative (lowercase) forms.
+    ///
+    /// The fill and stroke are overlaid onto this image following its :attr:`overlay_mode`, like the other draw entities.
+    ///
+    /// Parameters
+    /// ----------
+    /// path: str
+    ///     The path data, for example `M10 10 L90 10 L90 90 Z`
+    /// fill: Optional[:class:`.Pixel`], default: None
+    ///     The color to fill the path with. Open subpaths are implicitly closed when filling.
+    /// stroke: Optional[:class:`.Pixel`], default: None
+    ///     The color to stroke the outline of the path with.
+    /// thickness: int, default: 1
+    ///     The thickness of the stroke, in pixels.
+    /// cap: str, default: "butt"
+    ///     The shape drawn at the ends of open subpaths, one of `butt`, `round`, or `square`.
+    /// join: str, default: "miter"
+    ///     The shape drawn at the corners of the stroke, one of `miter`, `round`, or `bevel`.
+    ///     Sharp miters are drawn as bevels instead to avoid long spikes.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The path data is invalid or contains an unsupported command, or the cap or join is invalid
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// .. code-block:: python3
+    ///
+    ///     image.draw_path("M10 10 L90 10 L90 90 Z", fill=Pixel.from_rgb(255, 0, 0))
+    #[pyo3(
+        text_signature = "(self, path, fill = None, stroke = None, thickness = 1, cap = \"butt\", join = \"miter\")"
+    )]
+    #[args(
+        fill = "None",
+        stroke = "None",
+        thickness = "1",
+        cap = "\"butt\"",
+        join = "\"miter\""
+    )]
+    fn draw_path(
+        &mut self,
+        path: &str,
+        fill: Option<Pixel>,
+        stroke: Option<Pixel>,
+        thickness: u32,
+        cap: &str,
+        join: &str,
+    ) -> PyResult<()> {
+        let subpaths = path::parse(path)?;
+        let cap = path::get_line_cap(cap)?;
+        let join = path::get_line_join(join)?;
+
+        if let Some(fill) = fill {
+            path::fill(&mut self.inner, &subpaths, fill.inner);
+        }
+
+        if let Some(stroke) = stroke {
+            path::stroke(&mut self.inner, &subpaths, stroke.inner, thickness as f64, cap, join);
+        }
+
+        Ok(())
+    }
+
     /// Resizes this image in place to the given dimensions using the given resizing algorithm in place.
     ///
     /// Parameters
@@ -300,9 +448,67 @@ impl Image {
     ///     The target height to resize to
     /// algorithm: :class:`.ResizeAlgorithm`
     ///     The resize algorithm to use
-    #[pyo3(text_signature = "(self, width, height, algorithm)")]
-    fn resize(&mut self, width: u32, height: u32, algorithm: ResizeAlgorithm) {
-        self.inner.resize(width, height, algorithm.into());
+    /// premultiply_alpha: bool, default: True
+    ///     Whether to premultiply the color channels by the alpha channel while resampling `RGBA` images.
+    ///     This prevents the colors of fully transparent pixels from bleeding into their neighbours,
+    ///     which otherwise causes dark fringes around transparent edges. Ignored for other modes,
+    ///     and for :attr:`.ResizeAlgorithm.Nearest` since it never blends pixels.
+    #[pyo3(text_signature = "(self, width, height, algorithm, premultiply_alpha = True)")]
+    #[args(premultiply_alpha = "true")]
+    fn resize(
+        &mut self,
+        width: u32,
+        height: u32,
+        algorithm: ResizeAlgorithm,
+        premultiply_alpha: bool,
+    ) {
+        self.resize_inner(width, height, algorithm.into(), premultiply_alpha);
+    }
+
+    /// Generates the mipmap chain of this image, which is a list of progressively halved versions of it down to 1x1.
+    ///
+    /// The first level is a copy of this image, and each following level is downscaled from the previous level
+    /// using a box filter to avoid aliasing. Odd dimensions are rounded down, and dimensions never go below 1.
+    ///
+    /// `RGBA` images are premultiplied once and downscaled in premultiplied space throughout the chain,
+    /// so rounding errors do not accumulate from level to level.
+    ///
+    /// Returns
+    /// -------
+    /// List[:class:`Image`]
+    ///     The levels of the mipmap chain, from largest to smallest.
+    fn mipmaps(&self) -> Vec<Self> {
+        let mut levels = vec![self.clone()];
+        let (mut width, mut height) = self.dimensions();
+        let mut premultiplied = (self.mode() == "RGBA").then(|| self.premultiplied());
+
+        while width > 1 || height > 1 {
+            let (previous_width, previous_height) = (width, height);
+            width = (width / 2).max(1);
+            height = (height / 2).max(1);
+
+            let level = match premultiplied {
+                Some(ref mut pixels) => {
+                    *pixels = ops::resample(
+                        pixels,
+                        previous_width as usize,
+                        previous_height as usize,
+                        width as usize,
+                        height as usize,
+                        ril::ResizeAlgorithm::Box,
+                    );
+                    Self::from_inner(unpremultiplied(width, pixels))
+                }
+                None => {
+                    let mut level = levels[levels.len() - 1].clone();
+                    level.inner.resize(width, height, ril::ResizeAlgorithm::Box);
+                    level
+                }
+            };
+            levels.push(level);
+        }
+
+        levels
     }
 
     /// Encodes the image with the given encoding and returns `bytes`.
@@ -438,6 +644,89 @@ impl Image {
         Ok(())
     }
 
+    /// Fills the given bounding box of this image by repeatedly pasting the given pattern image, tiling it from the upper-left corner.
+    ///
+    /// Tiles that would extend past the region, or past the edges of this image, are clipped.
+    ///
+    /// If `mask` is provided, each tile will be masked with it. The mask must have the same dimensions as the pattern,
+    /// and only BitPixel images are currently supported for the masking image.
+    ///
+    /// Parameters
+    /// ----------
+    /// x1: int
+    ///    The x axis of the upper-left corner
+    /// y1: int
+    ///     The y axis of the upper-left corner
+    /// x2: int
+    ///     The x axis of the lower-right corner
+    /// y2: int
+    ///     The y axis of the lower-right corner
+    /// pattern: :class:`Image`
+    ///     The image to tile.
+    /// mask: Optional[:class:`Image`], default: None
+    ///     The mask to use for each tile, defaults to `None`
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     The mask provided is not of mode `BitPixel`
+    /// ValueError
+    ///     The mask does not have the same dimensions as the pattern
+    #[pyo3(text_signature = "(self, x1, y1, x2, y2, pattern, mask = None)")]
+    fn paste_tiled(
+        &mut self,
+        x1: u32,
+        y1: u32,
+        x2: u32,
+        y2: u32,
+        pattern: Self,
+        mask: Option<Self>,
+    ) -> PyResult<()> {
+        let mask = match mask {
+            Some(mask) if mask.mode() != "bitpixel" => {
+                return Err(Error::UnexpectedFormat(
+                    "bitpixel".to_string(),
+                    mask.mode().to_string(),
+                )
+                .into());
+            }
+            Some(mask) if mask.dimensions() != pattern.dimensions() => {
+                return Err(PyValueError::new_err(
+                    "The mask must have the same dimensions as the pattern",
+                ));
+            }
+            Some(mask) => Some(mask.inner.convert::<ril::BitPixel>()),
+            None => None,
+        };
+
+        let (x2, y2) = (x2.min(self.width()), y2.min(self.height()));
+        let (tile_width, tile_height) = pattern.dimensions();
+
+        for y in (y1..y2).step_by(tile_height as usize) {
+            for x in (x1..x2).step_by(tile_width as usize) {
+                let (width, height) = (tile_width.min(x2 - x), tile_height.min(y2 - y));
+
+                let mut tile = pattern.inner.clone();
+                if width < tile_width || height < tile_height {
+                    tile.crop(0, 0, width, height);
+                }
+
+                if let Some(mask) = &mask {
+                    let mut mask = mask.clone();
+                    if width < tile_width || height < tile_height {
+                        mask.crop(0, 0, width, height);
+                    }
+
+                    self.inner.paste_with_mask(x, y, tile, mask);
+                } else {
+                    self.inner.paste(x, y, tile);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Masks the alpha values of this image with the luminance values of the given single-channel L image.
     ///
     /// If you want to mask using the alpha values of the image instead of providing an L image, you can split the bands of the image and extract the alpha band.
@@ -467,6 +756,469 @@ impl Image {
         Ok(())
     }
 
+    /// Feathers, or softens the edges of this image in place by fading out its alpha values over `radius` pixels.
+    ///
+    /// Both the borders of the image and the boundaries of any existing transparent areas are feathered,
+    /// which makes cutouts blend in more naturally when pasted onto another image.
+    ///
+    /// Parameters
+    /// ----------
+    /// radius: int
+    ///     The distance in pixels over which the alpha values fade out.
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     The image is not of mode `RGBA`
+    #[pyo3(text_signature = "(self, radius)")]
+    fn feather(&mut self, radius: u32) -> PyResult<()> {
+        if self.mode() != "RGBA" {
+            return Err(Error::UnexpectedFormat("RGBA".to_string(), self.mode().to_string()).into());
+        }
+
+        let (width, height) = self.dimensions();
+        let mut pixels = self.rgba_pixels();
+        let mut coverage = pixels
+            .iter()
+            .map(|p| if p.a > 0 { 1. } else { 0. })
+            .collect::<Vec<f32>>();
+
+        ops::box_blur(&mut coverage, width as usize, height as usize, radius as usize);
+
+        for (pixel, coverage) in pixels.iter_mut().zip(coverage) {
+            // The blurred coverage is 0.5 right at an edge and reaches 1 `radius` pixels inside of it.
+            let factor = (2. * coverage - 1.).clamp(0., 1.);
+            pixel.a = (pixel.a as f32 * factor).round() as u8;
+        }
+
+        self.inner = self.with_rgba_pixels(pixels);
+
+        Ok(())
+    }
+
+    /// Fills the region marked by the given mask in place by propagating the surrounding pixels into it.
+    ///
+    /// This can be used to remove unwanted objects from an image, or to fill holes left by transparency.
+    /// Works best on small or thin regions, as larger regions will be filled with a smooth blur of their surroundings.
+    ///
+    /// The mask must have the same dimensions as this image. Pixels that are on (or non-zero for `L` masks) will be filled.
+    ///
+    /// Parameters
+    /// ----------
+    /// mask: :class:`Image`
+    ///     The mask marking the pixels to fill
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     The mask provided is not of mode `bitpixel` or `L`
+    /// ValueError
+    ///     The mask does not have the same dimensions as this image
+    #[pyo3(text_signature = "(self, mask)")]
+    fn inpaint(&mut self, mask: Self) -> PyResult<()> {
+        if mask.dimensions() != self.dimensions() {
+            return Err(PyValueError::new_err(
+                "The mask must have the same dimensions as the image",
+            ));
+        }
+
+        let (width, height) = self.dimensions();
+        let mask = mask.mask_values()?;
+        let mut pixels = self
+            .rgba_pixels()
+            .into_iter()
+            .map(|p| [p.r, p.g, p.b, p.a].map(f32::from))
+            .collect::<Vec<_>>();
+
+        ops::inpaint(&mut pixels, &mask, width as usize, height as usize);
+
+        self.inner = self.with_rgba_pixels(
+            pixels
+                .into_iter()
+                .map(|p| {
+                    let [r, g, b, a] = p.map(|c| c.round().clamp(0., 255.) as u8);
+                    ril::Rgba { r, g, b, a }
+                })
+                .collect(),
+        );
+
+        Ok(())
+    }
+
+    /// Chroma keys this image in place, making pixels close to `key_color` transparent. This converts the image to `RGBA`.
+    ///
+    /// The similarity of two colors is measured by their distance in RGB space, scaled to be between 0 and 255.
+    ///
+    /// By default the cutoff is hard, so pixels are either kept as is or made fully transparent. If `softness` is provided,
+    /// pixels whose distance is within `softness` past the tolerance are made partially transparent instead, which
+    /// smooths the edges of the keyed out area.
+    ///
+    /// If `spill_suppression` is provided, the tint of the key color that spills onto the remaining pixels, such as the
+    /// green fringes around a subject shot in front of a green screen, will be reduced. This only applies to key colors
+    /// with a single dominant channel, so it has no effect when keying out grays.
+    ///
+    /// Parameters
+    /// ----------
+    /// key_color: :class:`.Pixel`
+    ///     The color to key out.
+    /// tolerance: float
+    ///     The maximum distance, between 0 and 255, from the key color for a pixel to be made transparent.
+    /// spill_suppression: float, default: 0.0
+    ///     How strongly to suppress color spill, between 0 (not at all) and 1 (completely).
+    /// softness: float, default: 0.0
+    ///     The width of the band past the tolerance over which pixels fade from transparent to opaque.
+    #[pyo3(
+        text_signature = "(self, key_color, tolerance, spill_suppression = 0.0, softness = 0.0)"
+    )]
+    #[args(spill_suppression = "0.0", softness = "0.0")]
+    fn chroma_key(
+        &mut self,
+        key_color: Pixel,
+        tolerance: f32,
+        spill_suppression: f32,
+        softness: f32,
+    ) {
+        let key = cast_pixel_to_rgba(key_color.inner);
+        let key = [key.r, key.g, key.b].map(f32::from);
+        let spill_suppression = spill_suppression.clamp(0., 1.);
+        let softness = softness.max(0.);
+
+        // The channel that dominates the key color is the one that spills onto the subject. Key colors where no channel
+        // clearly stands out from the others, such as grays, have nothing to suppress.
+        let dominant = (0..3).find(|&i| {
+            let others = (0..3)
+                .filter(|&j| j != i)
+                .map(|j| key[j])
+                .fold(0., f32::max);
+
+            key[i] - others >= DOMINANT_CHANNEL_GAP
+        });
+
+        let pixels = self
+            .rgba_pixels()
+            .into_iter()
+            .map(|p| {
+                let mut color = [p.r, p.g, p.b].map(f32::from);
+                let distance = color
+                    .iter()
+                    .zip(key)
+                    .map(|(c, k)| (c - k) * (c - k))
+                    .sum::<f32>()
+                    .sqrt()
+                    / 3_f32.sqrt();
+
+                if distance <= tolerance {
+                    return ril::Rgba { a: 0, ..p };
+                }
+
+                if let Some(dominant) = dominant {
+                    let limit = (0..3)
+                        .filter(|&i| i != dominant)
+                        .map(|i| color[i])
+                        .fold(0., f32::max);
+
+                    if color[dominant] > limit {
+                        color[dominant] -= spill_suppression * (color[dominant] - limit);
+                    }
+                }
+
+                let opacity = if distance < tolerance + softness {
+                    (distance - tolerance) / softness
+                } else {
+                    1.
+                };
+
+                let [r, g, b] = color.map(|c| c.round() as u8);
+                ril::Rgba {
+                    r,
+                    g,
+                    b,
+                    a: (p.a as f32 * opacity).round() as u8,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.inner = RilImage::from_pixels(self.width(), pixels).convert::<Dynamic>();
+    }
+
+    /// Composites this image over a gray checkerboard, like the transparency backdrop found in image editors.
+    ///
+    /// This is useful for previewing transparent images, as the returned image is always opaque.
+    ///
+    /// Parameters
+    /// ----------
+    /// cell_size: int, default: 8
+    ///     The width and height of each cell of the checkerboard, in pixels.
+    ///
+    /// Returns
+    /// -------
+    /// :class:`Image`
+    ///     A new `RGB` image of this image composited over the checkerboard.
+    #[pyo3(text_signature = "(self, cell_size = 8)")]
+    #[args(cell_size = "8")]
+    fn on_checkerboard(&self, cell_size: u32) -> Self {
+        let cell_size = cell_size.max(1);
+        let width = self.width();
+
+        let pixels = self
+            .rgba_pixels()
+            .into_iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let (x, y) = (i as u32 % width, i as u32 / width);
+                let background = if (x / cell_size + y / cell_size) % 2 == 0 {
+                    255
+                } else {
+                    204
+                };
+
+                let blend = |c: u8| {
+                    ((c as u32 * p.a as u32 + background * (255 - p.a as u32) + 127) / 255) as u8
+                };
+
+                ril::Rgb {
+                    r: blend(p.r),
+                    g: blend(p.g),
+                    b: blend(p.b),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self::from_inner(RilImage::from_pixels(width, pixels).convert::<Dynamic>())
+    }
+
+    /// Finds the contours, or boundaries, of each connected region in this mask image.
+    ///
+    /// Pixels that are on (or non-zero for `L` masks) are considered part of a region, and diagonally adjacent pixels are
+    /// considered connected. The outer boundary of each region is traced clockwise, starting from the upper-left-most pixel of the region.
+    ///
+    /// Holes are regions of pixels that are off and fully enclosed by a region. The boundary of a hole is made of the pixels of
+    /// the enclosing region that border it, and is traced counter-clockwise, starting from the pixel to the left of the
+    /// upper-left-most pixel of the hole.
+    ///
+    /// Parameters
+    /// ----------
+    /// holes: bool, default: False
+    ///     Whether to also find the boundaries of holes.
+    ///
+    /// Returns
+    /// -------
+    /// Tuple[List[List[Tuple[int, int]]], List[Tuple[int, List[Tuple[int, int]]]]]
+    ///     The coordinates of the boundary pixels of each region, along with the index of the enclosing region's boundary
+    ///     and the coordinates of the boundary pixels of each hole. The list of holes is empty if `holes` is False.
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     The image is not of mode `bitpixel` or `L`
+    #[pyo3(text_signature = "(self, holes = False)")]
+    #[args(holes = "false")]
+    #[allow(clippy::type_complexity)]
+    fn find_contours(
+        &self,
+        holes: bool,
+    ) -> Result<(Vec<Vec<(u32, u32)>>, Vec<(usize, Vec<(u32, u32)>)>), Error> {
+        let (width, height) = self.dimensions();
+
+        Ok(ops::find_contours(
+            &self.mask_values()?,
+            width as usize,
+            height as usize,
+            holes,
+        ))
+    }
+
+    /// Labels each connected region in this mask image with a unique id.
+    ///
+    /// Pixels that are on (or non-zero for `L` masks) are considered part of a region. Regions are numbered from 1 in the
+    /// order they are first encountered when scanning the image row by row, and pixels that are not part of any region are labeled 0.
+    ///
+    /// Parameters
+    /// ----------
+    /// connectivity: int, default: 4
+    ///     Either 4, to only consider horizontally and vertically adjacent pixels connected, or 8, to also consider
+    ///     diagonally adjacent pixels connected.
+    ///
+    /// Returns
+    /// -------
+    /// Tuple[List[List[int]], int, List[Tuple[int, int, int, int, int]]]
+    ///     A 2D list of the label of each pixel where each list is a row, the amount of regions, and the bounding box
+    ///     `(x1, y1, x2, y2)` and area of each region, in label order. The lower-right corner of each bounding box is exclusive.
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     The image is not of mode `bitpixel` or `L`
+    /// ValueError
+    ///     The connectivity is not 4 or 8
+    #[pyo3(text_signature = "(self, connectivity = 4)")]
+    #[args(connectivity = "4")]
+    #[allow(clippy::type_complexity)]
+    fn connected_components(
+        &self,
+        connectivity: u32,
+    ) -> PyResult<(Vec<Vec<u32>>, u32, Vec<(u32, u32, u32, u32, u32)>)> {
+        let eight = match connectivity {
+            4 => false,
+            8 => true,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Expected connectivity of 4 or 8, got `{}`",
+                    connectivity
+                )))
+            }
+        };
+
+        let (width, height) = self.dimensions();
+        let (labels, count) =
+            ops::label_components(&self.mask_values()?, width as usize, height as usize, eight);
+
+        let mut components = vec![(u32::MAX, u32::MAX, 0, 0, 0); count as usize];
+        for (i, &label) in labels.iter().enumerate().filter(|(_, &label)| label != 0) {
+            let (x, y) = (i as u32 % width, i as u32 / width);
+            let (x1, y1, x2, y2, area) = &mut components[label as usize - 1];
+
+            *x1 = (*x1).min(x);
+            *y1 = (*y1).min(y);
+            *x2 = (*x2).max(x + 1);
+            *y2 = (*y2).max(y + 1);
+            *area += 1;
+        }
+
+        Ok((
+            labels
+                .chunks(width as usize)
+                .map(<[u32]>::to_vec)
+                .collect(),
+            count,
+            components,
+        ))
+    }
+
+    /// Computes the distance transform of this mask image, where each pixel that is on (or non-zero for `L` masks)
+    /// is set to its distance in pixels to the nearest pixel that is off.
+    ///
+    /// Distances are approximated using a chamfer distance transform, and are rounded and capped at 255.
+    /// Pixels that are off are set to 0, and if every pixel is on, every pixel is set to 255.
+    ///
+    /// Returns
+    /// -------
+    /// :class:`Image`
+    ///     A new `L` image containing the distance of each pixel.
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     The image is not of mode `bitpixel` or `L`
+    fn distance_transform(&self) -> Result<Self, Error> {
+        let (width, height) = self.dimensions();
+        let pixels = ops::distance_transform(&self.mask_values()?, width as usize, height as usize)
+            .into_iter()
+            .map(|distance| ril::L(distance.round().min(255.) as u8))
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_inner(
+            RilImage::from_pixels(width, pixels).convert::<Dynamic>(),
+        ))
+    }
+
+    /// Finds where the given template image best matches this image, such as for locating an icon within a screenshot.
+    ///
+    /// Both images are compared in grayscale, and each position is scored by the normalized cross-correlation
+    /// of the template with the region of this image it covers.
+    ///
+    /// Correlation is undefined for flat regions, so a template of a single solid color only matches regions of the exact same
+    /// luminance, which score 1, while every other region scores 0. Flat regions likewise score 0 against other templates.
+    ///
+    /// .. warning:: **This function involves heavy operation**
+    ///
+    ///     Every position of the template is compared pixel by pixel, so this is a heavy operation for larger images and templates.
+    ///     Consider downscaling both images beforehand.
+    ///
+    /// Parameters
+    /// ----------
+    /// template: :class:`Image`
+    ///     The image to search for.
+    ///
+    /// Returns
+    /// -------
+    /// Tuple[int, int, float]
+    ///     The x and y axis of the upper-left corner of the best match, and its score between -1 and 1,
+    ///     where 1 is a perfect match.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The template is larger than this image
+    #[pyo3(text_signature = "(self, template)")]
+    fn match_template(&self, template: Self) -> PyResult<(u32, u32, f32)> {
+        let (width, height) = self.dimensions();
+        let (template_width, template_height) = template.dimensions();
+
+        if template_width > width || template_height > height {
+            return Err(PyValueError::new_err(
+                "The template must not be larger than the image",
+            ));
+        }
+
+        let (x, y, score) = ops::match_template(
+            &self.luminance_values(),
+            width as usize,
+            height as usize,
+            &template.luminance_values(),
+            template_width as usize,
+            template_height as usize,
+        );
+
+        Ok((x as u32, y as u32, score))
+    }
+
+    /// Applies a bloom, or glow effect to this image in place.
+    ///
+    /// The areas of this image that are brighter than `threshold` are extracted, blurred by `radius`,
+    /// and then added back onto the image. The alpha channel is left untouched.
+    ///
+    /// Parameters
+    /// ----------
+    /// threshold: int
+    ///     The minimum luminance, between 0 and 255, for a pixel to glow.
+    /// intensity: float
+    ///     How strongly the glow is added back onto the image, where 1 adds it at full strength.
+    /// radius: int
+    ///     The radius of the blur applied to the bright areas, in pixels.
+    #[pyo3(text_signature = "(self, threshold, intensity, radius)")]
+    fn glow(&mut self, threshold: u8, intensity: f32, radius: u32) {
+        let (width, height) = self.dimensions();
+        let mut pixels = self.rgba_pixels();
+
+        let mut channels = [Vec::new(), Vec::new(), Vec::new()];
+        for p in &pixels {
+            let luminance = 0.299 * p.r as f32 + 0.587 * p.g as f32 + 0.114 * p.b as f32;
+            let bright = luminance >= threshold as f32;
+
+            for (channel, value) in channels.iter_mut().zip([p.r, p.g, p.b]) {
+                channel.push(if bright { value as f32 } else { 0. });
+            }
+        }
+
+        // Three box blurs closely approximate a gaussian blur.
+        for channel in &mut channels {
+            for _ in 0..3 {
+                ops::box_blur(channel, width as usize, height as usize, radius as usize);
+            }
+        }
+
+        for (i, p) in pixels.iter_mut().enumerate() {
+            let add = |value: u8, glow: f32| (value as f32 + glow * intensity).round().clamp(0., 255.) as u8;
+
+            p.r = add(p.r, channels[0][i]);
+            p.g = add(p.g, channels[1][i]);
+            p.b = add(p.b, channels[2][i]);
+        }
+
+        self.inner = self.with_rgba_pixels(pixels);
+    }
+
     /// Mirrors, or flips this image horizontally (about the y-axis) in place.
     fn mirror(&mut self) {
         self.inner.mirror();
@@ -557,8 +1309,149 @@ impl Image {
     }
 }
 
+/// Converts pixels with premultiplied alpha, as returned by `Image::premultiplied`, back to a straight alpha image.
+fn unpremultiplied(width: u32, pixels: &[[f32; 4]]) -> RilImage {
+    let pixels = pixels
+        .iter()
+        .map(|&[r, g, b, a]| {
+            let a = a.clamp(0., 255.);
+
+            if a.round() == 0. {
+                return ril::Rgba {
+                    r: 0,
+                    g: 0,
+                    b: 0,
+                    a: 0,
+                };
+            }
+
+            let unpremultiply = |c: f32| (c * 255. / a).round().clamp(0., 255.) as u8;
+            ril::Rgba {
+                r: unpremultiply(r),
+                g: unpremultiply(g),
+                b: unpremultiply(b),
+                a: a.round() as u8,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    RilImage::from_pixels(width, pixels).convert::<Dynamic>()
+}
+
 impl Image {
     fn from_inner(image: RilImage) -> Self {
         Self { inner: image }
     }
+
+    /// Returns the pixels of this image converted to RGBA, in row-major order.
+    fn rgba_pixels(&self) -> Vec<ril::Rgba> {
+        let image = self.inner.clone().convert::<ril::Rgba>();
+        let (width, height) = image.dimensions();
+
+        let mut pixels = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.push(*image.pixel(x, y));
+            }
+        }
+
+        pixels
+    }
+
+    /// Resizes this image in place, premultiplying the alpha channel while resampling if requested and the image is `RGBA`.
+    ///
+    /// Nearest neighbor resampling never blends pixels, so it is always done without premultiplying.
+    fn resize_inner(
+        &mut self,
+        width: u32,
+        height: u32,
+        algorithm: ril::ResizeAlgorithm,
+        premultiply_alpha: bool,
+    ) {
+        if !premultiply_alpha
+            || self.mode() != "RGBA"
+            || matches!(algorithm, ril::ResizeAlgorithm::Nearest)
+        {
+            self.inner.resize(width, height, algorithm);
+            return;
+        }
+
+        let pixels = ops::resample(
+            &self.premultiplied(),
+            self.width() as usize,
+            self.height() as usize,
+            width as usize,
+            height as usize,
+            algorithm,
+        );
+
+        self.inner = unpremultiplied(width, &pixels);
+    }
+
+    /// Returns the pixels of this image with their color channels premultiplied by their alpha channel.
+    ///
+    /// These are kept at full precision, since rounding them would lose most of the color of nearly transparent pixels.
+    fn premultiplied(&self) -> Vec<[f32; 4]> {
+        self.rgba_pixels()
+            .into_iter()
+            .map(|p| {
+                let a = f32::from(p.a);
+                let premultiply = |c: u8| f32::from(c) * a / 255.;
+
+                [premultiply(p.r), premultiply(p.g), premultiply(p.b), a]
+            })
+            .collect()
+    }
+
+    /// Returns the luminance of each pixel of this image, in row-major order.
+    fn luminance_values(&self) -> Vec<f32> {
+        let image = self.inner.clone().convert::<ril::L>();
+        let (width, height) = image.dimensions();
+
+        let mut values = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                values.push(f32::from(image.pixel(x, y).value()));
+            }
+        }
+
+        values
+    }
+
+    /// Returns whether each pixel of this mask image is set, in row-major order.
+    ///
+    /// `bitpixel` pixels are set if they are on, and `L` pixels are set if they are non-zero.
+    fn mask_values(&self) -> Result<Vec<bool>, Error> {
+        let (width, height) = self.dimensions();
+        let mut values = Vec::with_capacity((width * height) as usize);
+
+        for y in 0..height {
+            for x in 0..width {
+                values.push(match *self.inner.pixel(x, y) {
+                    Dynamic::BitPixel(v) => v.value(),
+                    Dynamic::L(v) => v.value() > 0,
+                    _ => {
+                        return Err(Error::UnexpectedFormat(
+                            "bitpixel or L".to_string(),
+                            self.mode().to_string(),
+                        ))
+                    }
+                });
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Creates an image with the same width and mode as this image from the given row-major RGBA pixels.
+    fn with_rgba_pixels(&self, pixels: Vec<ril::Rgba>) -> RilImage {
+        let image = RilImage::from_pixels(self.width(), pixels).convert::<Dynamic>();
+
+        match self.mode() {
+            "bitpixel" => image.convert::<ril::BitPixel>().convert::<Dynamic>(),
+            "L" => image.convert::<ril::L>().convert::<Dynamic>(),
+            "RGB" => image.convert::<ril::Rgb>().convert::<Dynamic>(),
+            _ => image,
+        }
+    }
 }
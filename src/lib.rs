@@ -7,6 +7,8 @@
 mod draw;
 mod error;
 mod image;
+mod ops;
+mod path;
 mod pixels;
 mod sequence;
 mod types;
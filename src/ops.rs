@@ -0,0 +1,689 @@
+//! Low-level image processing algorithms that operate on raw pixel buffers.
+//!
+//! These are used to implement the higher-level methods on :class:`.Image` that
+//! are not directly provided by ril.
+
+/// Blurs the given single-channel buffer in place with a box filter of the given radius.
+///
+/// Samples that fall outside of the buffer are treated as `0`, so values near the edges fade out.
+pub fn box_blur(values: &mut [f32], width: usize, height: usize, radius: usize) {
+    if radius == 0 {
+        return;
+    }
+
+    let mut sums = Vec::with_capacity(width.max(height) + 1);
+
+    for y in 0..height {
+        blur_line(values, y * width, 1, width, radius, &mut sums);
+    }
+
+    for x in 0..width {
+        blur_line(values, x, width, height, radius, &mut sums);
+    }
+}
+
+fn blur_line(
+    values: &mut [f32],
+    start: usize,
+    stride: usize,
+    len: usize,
+    radius: usize,
+    sums: &mut Vec<f32>,
+) {
+    sums.clear();
+    sums.push(0.);
+
+    for i in 0..len {
+        let sum = sums[i] + values[start + i * stride];
+        sums.push(sum);
+    }
+
+    let window = (2 * radius + 1) as f32;
+
+    for i in 0..len {
+        let lo = i.saturating_sub(radius);
+        let hi = (i + radius + 1).min(len);
+
+        values[start + i * stride] = (sums[hi] - sums[lo]) / window;
+    }
+}
+
+/// Returns the support radius and kernel of the given resampling filter, matching the filters used by ril.
+///
+/// Nearest neighbor resampling has no kernel, and is handled separately by [`resample`].
+fn resampling_filter(algorithm: ril::ResizeAlgorithm) -> (f64, fn(f64) -> f64) {
+    fn sinc(x: f64) -> f64 {
+        if x == 0. {
+            1.
+        } else {
+            let x = x * std::f64::consts::PI;
+            x.sin() / x
+        }
+    }
+
+    fn cubic(x: f64, b: f64, c: f64) -> f64 {
+        let x = x.abs();
+
+        if x < 1. {
+            ((12. - 9. * b - 6. * c) * x.powi(3)
+                + (-18. + 12. * b + 6. * c) * x.powi(2)
+                + (6. - 2. * b))
+                / 6.
+        } else if x < 2. {
+            ((-b - 6. * c) * x.powi(3)
+                + (6. * b + 30. * c) * x.powi(2)
+                + (-12. * b - 48. * c) * x
+                + (8. * b + 24. * c))
+                / 6.
+        } else {
+            0.
+        }
+    }
+
+    match algorithm {
+        ril::ResizeAlgorithm::Nearest | ril::ResizeAlgorithm::Box => {
+            (0.5, |x| if (-0.5..0.5).contains(&x) { 1. } else { 0. })
+        }
+        ril::ResizeAlgorithm::Bilinear => (1., |x| (1. - x.abs()).max(0.)),
+        ril::ResizeAlgorithm::Hamming => (1., |x| {
+            if x.abs() >= 1. {
+                0.
+            } else {
+                sinc(x) * (0.54 + 0.46 * (x * std::f64::consts::PI).cos())
+            }
+        }),
+        ril::ResizeAlgorithm::Bicubic => (2., |x| cubic(x, 0., 0.5)),
+        ril::ResizeAlgorithm::Mitchell => (2., |x| cubic(x, 1. / 3., 1. / 3.)),
+        ril::ResizeAlgorithm::Lanczos3 => (3., |x| {
+            if x.abs() >= 3. {
+                0.
+            } else {
+                sinc(x) * sinc(x / 3.)
+            }
+        }),
+    }
+}
+
+/// Computes the first source sample and the normalized weights of the samples contributing to each of the `to`
+/// output samples, when resampling a line of `from` samples.
+fn resampling_weights(
+    from: usize,
+    to: usize,
+    algorithm: ril::ResizeAlgorithm,
+) -> Vec<(usize, Vec<f32>)> {
+    let scale = from as f64 / to as f64;
+
+    if matches!(algorithm, ril::ResizeAlgorithm::Nearest) {
+        return (0..to)
+            .map(|i| {
+                (
+                    (((i as f64 + 0.5) * scale) as usize).min(from - 1),
+                    vec![1.],
+                )
+            })
+            .collect();
+    }
+
+    let (support, kernel) = resampling_filter(algorithm);
+    // When downscaling, the kernel is stretched so that every source sample contributes to the output.
+    let filter_scale = scale.max(1.);
+    let support = support * filter_scale;
+
+    (0..to)
+        .map(|i| {
+            let center = (i as f64 + 0.5) * scale;
+            let start = (center - support).floor().max(0.) as usize;
+            let end = ((center + support).ceil() as usize).min(from);
+
+            let weights = (start..end)
+                .map(|j| kernel((j as f64 + 0.5 - center) / filter_scale))
+                .collect::<Vec<_>>();
+            let total = weights.iter().sum::<f64>();
+
+            (
+                start,
+                weights.into_iter().map(|w| (w / total) as f32).collect(),
+            )
+        })
+        .collect()
+}
+
+/// Resamples the given buffer of four-channel pixels to the given dimensions at full precision.
+///
+/// This mirrors the filters of ril's `resize`, but works on floating point samples so that values such as
+/// premultiplied colors do not lose precision while resampling.
+pub fn resample(
+    pixels: &[[f32; 4]],
+    width: usize,
+    height: usize,
+    new_width: usize,
+    new_height: usize,
+    algorithm: ril::ResizeAlgorithm,
+) -> Vec<[f32; 4]> {
+    let accumulate = |samples: &mut dyn Iterator<Item = ([f32; 4], f32)>| {
+        samples.fold([0.; 4], |mut total, (pixel, weight)| {
+            for (t, c) in total.iter_mut().zip(pixel) {
+                *t += c * weight;
+            }
+            total
+        })
+    };
+
+    let horizontal = resampling_weights(width, new_width, algorithm);
+    let mut rows = Vec::with_capacity(new_width * height);
+
+    for y in 0..height {
+        for (start, weights) in &horizontal {
+            rows.push(accumulate(
+                &mut weights
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &w)| (pixels[y * width + start + k], w)),
+            ));
+        }
+    }
+
+    let vertical = resampling_weights(height, new_height, algorithm);
+    let mut resampled = Vec::with_capacity(new_width * new_height);
+
+    for (start, weights) in &vertical {
+        for x in 0..new_width {
+            resampled.push(accumulate(
+                &mut weights
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &w)| (rows[(start + k) * new_width + x], w)),
+            ));
+        }
+    }
+
+    resampled
+}
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Returns the indices of the in-bounds neighbours of the pixel at the given index.
+fn neighbours(
+    index: usize,
+    width: usize,
+    height: usize,
+    offsets: &'static [(isize, isize)],
+) -> impl Iterator<Item = usize> {
+    let (x, y) = ((index % width) as isize, (index / width) as isize);
+
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let (nx, ny) = (x + dx, y + dy);
+
+        if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+            None
+        } else {
+            Some(ny as usize * width + nx as usize)
+        }
+    })
+}
+
+/// The amount of diffusion passes used to smooth the filled region when inpainting.
+const INPAINT_ITERATIONS: usize = 64;
+
+/// Fills the pixels marked by `mask` by propagating the surrounding pixels into them.
+///
+/// The masked region is first filled from its boundary inwards, with each pixel taking the average of its
+/// already known neighbours. The filled region is then smoothed by repeatedly diffusing the values of
+/// neighbouring pixels into it.
+pub fn inpaint(pixels: &mut [[f32; 4]], mask: &[bool], width: usize, height: usize) {
+    let mut known = mask.iter().map(|masked| !masked).collect::<Vec<_>>();
+    let region = (0..pixels.len()).filter(|&i| mask[i]).collect::<Vec<_>>();
+    let mut remaining = region.clone();
+
+    loop {
+        let filled = remaining
+            .iter()
+            .filter_map(|&i| {
+                average(
+                    pixels,
+                    neighbours(i, width, height, &NEIGHBOURS_8).filter(|&n| known[n]),
+                )
+                .map(|value| (i, value))
+            })
+            .collect::<Vec<_>>();
+
+        if filled.is_empty() {
+            break;
+        }
+
+        for &(i, value) in &filled {
+            pixels[i] = value;
+            known[i] = true;
+        }
+
+        remaining.retain(|&i| !known[i]);
+    }
+
+    for _ in 0..INPAINT_ITERATIONS {
+        for &i in &region {
+            if let Some(value) = average(pixels, neighbours(i, width, height, &NEIGHBOURS_4)) {
+                pixels[i] = value;
+            }
+        }
+    }
+}
+
+fn average(pixels: &[[f32; 4]], indices: impl Iterator<Item = usize>) -> Option<[f32; 4]> {
+    let mut sum = [0.; 4];
+    let mut count = 0;
+
+    for i in indices {
+        for (total, value) in sum.iter_mut().zip(pixels[i]) {
+            *total += value;
+        }
+        count += 1;
+    }
+
+    if count == 0 {
+        return None;
+    }
+
+    Some(sum.map(|total| total / count as f32))
+}
+
+/// Labels the connected regions of set pixels in the given mask.
+///
+/// Returns the label of each pixel, where `0` is unset and regions are numbered from `1` in the order
+/// they are first encountered, along with the amount of regions.
+pub fn label_components(
+    mask: &[bool],
+    width: usize,
+    height: usize,
+    eight: bool,
+) -> (Vec<u32>, u32) {
+    let offsets: &'static [(isize, isize)] = if eight { &NEIGHBOURS_8 } else { &NEIGHBOURS_4 };
+    let mut labels = vec![0; mask.len()];
+    let mut count = 0;
+    let mut stack = Vec::new();
+
+    for (i, &set) in mask.iter().enumerate() {
+        if !set || labels[i] != 0 {
+            continue;
+        }
+
+        count += 1;
+        labels[i] = count;
+        stack.push(i);
+
+        while let Some(j) = stack.pop() {
+            for n in neighbours(j, width, height, offsets) {
+                if mask[n] && labels[n] == 0 {
+                    labels[n] = count;
+                    stack.push(n);
+                }
+            }
+        }
+    }
+
+    (labels, count)
+}
+
+/// The Moore neighbourhood in clockwise order, starting from the west.
+const MOORE: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+
+/// Traces the boundary of the region containing `start` using Moore-neighbour tracing, keeping the region on the right.
+///
+/// `back` is the direction in [`MOORE`] of an unset neighbour of `start`, which decides which boundary is traced.
+fn trace_boundary(
+    width: usize,
+    height: usize,
+    start: usize,
+    back: usize,
+    inside: impl Fn(usize) -> bool,
+) -> Vec<usize> {
+    let position = |i: usize| ((i % width) as isize, (i / width) as isize);
+    let at = |(x, y): (isize, isize)| {
+        if x < 0 || y < 0 || x >= width as isize || y >= height as isize {
+            return None;
+        }
+
+        let i = y as usize * width + x as usize;
+        if inside(i) {
+            Some(i)
+        } else {
+            None
+        }
+    };
+
+    // Each state is a boundary pixel along with the direction of the unset pixel we backtracked from.
+    let initial = (start, back);
+    let mut state = initial;
+    let mut first = None;
+    let mut boundary = vec![start];
+
+    for _ in 0..4 * width * height + 8 {
+        let (current, back) = state;
+        let (x, y) = position(current);
+
+        let next = (1..=8).map(|k| (back + k) % 8).find_map(|dir| {
+            let (dx, dy) = MOORE[dir];
+            at((x + dx, y + dy)).map(|n| (n, dir))
+        });
+
+        let (n, dir) = match next {
+            Some(next) => next,
+            None => break,
+        };
+
+        let (nx, ny) = position(n);
+        let (bx, by) = MOORE[(dir + 7) % 8];
+        let back = MOORE
+            .iter()
+            .position(|&offset| offset == (x + bx - nx, y + by - ny))
+            .expect("consecutive neighbours are adjacent");
+
+        state = (n, back);
+
+        // The trace is complete once it returns to a state it has already been in.
+        if state == initial || Some(state) == first {
+            break;
+        }
+
+        first.get_or_insert(state);
+        boundary.push(n);
+    }
+
+    if boundary.len() > 1 && boundary.last() == Some(&start) {
+        boundary.pop();
+    }
+
+    boundary
+}
+
+/// Traces the outer boundary of each labeled region, in label order.
+fn trace_labels(labels: &[u32], count: u32, width: usize, height: usize) -> Vec<(u32, Vec<usize>)> {
+    let mut starts = vec![None; count as usize + 1];
+
+    for (i, &label) in labels.iter().enumerate() {
+        if label != 0 && starts[label as usize].is_none() {
+            starts[label as usize] = Some(i);
+        }
+    }
+
+    starts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(label, start)| {
+            let label = label as u32;
+
+            start.map(|start| {
+                // Since the start pixel is the first in its region, its western neighbour is always unset.
+                (
+                    label,
+                    trace_boundary(width, height, start, 0, |i| labels[i] == label),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Finds the boundaries of each 8-connected region of set pixels in the given mask.
+///
+/// If `holes` is true, the inner boundaries of each 4-connected region of unset pixels that is fully enclosed by set
+/// pixels are also returned, each along with the index of the outer boundary of the region enclosing it.
+pub fn find_contours(
+    mask: &[bool],
+    width: usize,
+    height: usize,
+    holes: bool,
+) -> (Vec<Vec<(u32, u32)>>, Vec<(usize, Vec<(u32, u32)>)>) {
+    let coordinates = |boundary: Vec<usize>| {
+        boundary
+            .into_iter()
+            .map(|i| ((i % width) as u32, (i / width) as u32))
+            .collect::<Vec<_>>()
+    };
+
+    let (labels, count) = label_components(mask, width, height, true);
+    let contours = trace_labels(&labels, count, width, height)
+        .into_iter()
+        .map(|(_, boundary)| coordinates(boundary))
+        .collect();
+
+    if !holes {
+        return (contours, Vec::new());
+    }
+
+    let background = mask.iter().map(|set| !set).collect::<Vec<_>>();
+    let (background, background_count) = label_components(&background, width, height, false);
+
+    let mut enclosed = vec![true; background_count as usize + 1];
+    for (i, &label) in background.iter().enumerate() {
+        let (x, y) = (i % width, i / width);
+
+        if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+            enclosed[label as usize] = false;
+        }
+    }
+
+    let mut inner = Vec::new();
+    for (i, &hole) in background.iter().enumerate() {
+        if hole == 0 || !enclosed[hole as usize] {
+            continue;
+        }
+        // Each hole is only traced once, starting from its first pixel.
+        enclosed[hole as usize] = false;
+
+        // The western neighbour of the first pixel of a hole is always set, since the hole does not touch the border
+        // and that neighbour would otherwise be part of the hole. Tracing from it with the hole to its east follows the
+        // set pixels bordering the hole rather than the outer boundary of its region.
+        let start = i - 1;
+        let label = labels[start];
+
+        inner.push((
+            label as usize - 1,
+            coordinates(trace_boundary(width, height, start, 4, |i| {
+                labels[i] == label
+            })),
+        ));
+    }
+
+    (contours, inner)
+}
+
+/// Approximates the Euclidean distance from each set pixel in the given mask to the nearest unset pixel using a
+/// two-pass 3-4 chamfer distance transform. Unset pixels have a distance of `0`.
+///
+/// If there are no unset pixels, every set pixel has a distance of [`f32::INFINITY`].
+pub fn distance_transform(mask: &[bool], width: usize, height: usize) -> Vec<f32> {
+    const UNREACHED: u32 = u32::MAX / 2;
+    const STRAIGHT: u32 = 3;
+    const DIAGONAL: u32 = 4;
+
+    let mut distances = mask
+        .iter()
+        .map(|&set| if set { UNREACHED } else { 0 })
+        .collect::<Vec<_>>();
+
+    for y in 0..height {
+        for x in 0..width {
+            let i = y * width + x;
+            let mut best = distances[i];
+
+            if x > 0 {
+                best = best.min(distances[i - 1] + STRAIGHT);
+            }
+
+            if y > 0 {
+                best = best.min(distances[i - width] + STRAIGHT);
+
+                if x > 0 {
+                    best = best.min(distances[i - width - 1] + DIAGONAL);
+                }
+
+                if x + 1 < width {
+                    best = best.min(distances[i - width + 1] + DIAGONAL);
+                }
+            }
+
+            distances[i] = best;
+        }
+    }
+
+    for y in (0..height).rev() {
+        for x in (0..width).rev() {
+            let i = y * width + x;
+            let mut best = distances[i];
+
+            if x + 1 < width {
+                best = best.min(distances[i + 1] + STRAIGHT);
+            }
+
+            if y + 1 < height {
+                best = best.min(distances[i + width] + STRAIGHT);
+
+                if x + 1 < width {
+                    best = best.min(distances[i + width + 1] + DIAGONAL);
+                }
+
+                if x > 0 {
+                    best = best.min(distances[i + width - 1] + DIAGONAL);
+                }
+            }
+
+            distances[i] = best;
+        }
+    }
+
+    distances
+        .into_iter()
+        .map(|distance| {
+            if distance >= UNREACHED {
+                f32::INFINITY
+            } else {
+                distance as f32 / STRAIGHT as f32
+            }
+        })
+        .collect()
+}
+
+/// The tolerance, relative to the squared sum of a window, below which its variance is considered zero.
+///
+/// The pixel values are integers, so the variance of a window that is not flat is at least 0.5.
+const FLAT_TOLERANCE: f64 = 1e-12;
+
+/// Finds the position where the given template best matches the given single-channel image, by sliding the template
+/// over every position and computing their normalized cross-correlation.
+///
+/// Returns the upper-left corner of the best match and its score, between -1 and 1. The template must not be larger
+/// than the image.
+///
+/// Correlation is undefined for flat regions, so a flat template instead scores 1 on windows filled with the same value
+/// and 0 everywhere else, and flat windows score 0 against templates that are not flat.
+pub fn match_template(
+    image: &[f32],
+    width: usize,
+    height: usize,
+    template: &[f32],
+    template_width: usize,
+    template_height: usize,
+) -> (usize, usize, f32) {
+    let area = (template_width * template_height) as f64;
+    let flat_value = template
+        .iter()
+        .all(|&v| v == template[0])
+        .then(|| template[0]);
+    let template_mean = template.iter().map(|&v| v as f64).sum::<f64>() / area;
+    let template = template
+        .iter()
+        .map(|&v| v as f64 - template_mean)
+        .collect::<Vec<_>>();
+    let template_variance = template.iter().map(|v| v * v).sum::<f64>();
+
+    // Summed-area tables for the sum and squared sum of the image, used to compute each window's variance.
+    let stride = width + 1;
+    let mut sums = vec![0.; stride * (height + 1)];
+    let mut squares = vec![0.; stride * (height + 1)];
+
+    for y in 0..height {
+        for x in 0..width {
+            let v = image[y * width + x] as f64;
+            let i = (y + 1) * stride + x + 1;
+
+            sums[i] = v + sums[i - 1] + sums[i - stride] - sums[i - stride - 1];
+            squares[i] = v * v + squares[i - 1] + squares[i - stride] - squares[i - stride - 1];
+        }
+    }
+
+    let window = |table: &[f64], x: usize, y: usize| {
+        let (x2, y2) = (x + template_width, y + template_height);
+
+        table[y2 * stride + x2] - table[y * stride + x2] - table[y2 * stride + x]
+            + table[y * stride + x]
+    };
+
+    let mut best = (0, 0, f32::NEG_INFINITY);
+
+    for y in 0..=height - template_height {
+        for x in 0..=width - template_width {
+            let score = if let Some(value) = flat_value {
+                let matches = (y..y + template_height).all(|wy| {
+                    let row = wy * width + x;
+
+                    image[row..row + template_width].iter().all(|&v| v == value)
+                });
+
+                if matches {
+                    1.
+                } else {
+                    0.
+                }
+            } else {
+                let sum = window(&sums, x, y);
+                let squared = window(&squares, x, y);
+                let variance = (squared - sum * sum / area).max(0.);
+
+                // The variance of a flat window only differs from zero by the rounding error of the subtraction above,
+                // which is relative to the magnitude of its squared sum.
+                if variance <= squared * FLAT_TOLERANCE {
+                    0.
+                } else {
+                    let mut correlation = 0.;
+
+                    for ty in 0..template_height {
+                        let row = (y + ty) * width + x;
+
+                        for (t, &v) in template[ty * template_width..(ty + 1) * template_width]
+                            .iter()
+                            .zip(&image[row..row + template_width])
+                        {
+                            correlation += t * v as f64;
+                        }
+                    }
+
+                    correlation / (template_variance * variance).sqrt()
+                }
+            };
+
+            if score as f32 > best.2 {
+                best = (x, y, score as f32);
+            }
+        }
+    }
+
+    best
+}
@@ -0,0 +1,535 @@
+//! Parsing and rasterization of SVG-like path strings.
+
+use std::{cmp::Ordering, iter::Peekable, mem, vec::IntoIter};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use ril::{Dynamic, Image as RilImage};
+
+type Point = (f64, f64);
+
+/// A continuous run of points within a path, started by a moveto command.
+pub struct Subpath {
+    pub points: Vec<Point>,
+    pub closed: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Command(char),
+    Number(f64),
+}
+
+fn tokenize(path: &str) -> PyResult<Vec<Token>> {
+    let chars = path.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() || c == ',' {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() {
+            tokens.push(Token::Command(c));
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        if matches!(c, '+' | '-') {
+            i += 1;
+        }
+
+        let mut seen_dot = false;
+        while i < chars.len() && (chars[i].is_ascii_digit() || (chars[i] == '.' && !seen_dot)) {
+            seen_dot |= chars[i] == '.';
+            i += 1;
+        }
+
+        if i < chars.len() && matches!(chars[i], 'e' | 'E') {
+            let mut j = i + 1;
+            if j < chars.len() && matches!(chars[j], '+' | '-') {
+                j += 1;
+            }
+
+            if j < chars.len() && chars[j].is_ascii_digit() {
+                while j < chars.len() && chars[j].is_ascii_digit() {
+                    j += 1;
+                }
+                i = j;
+            }
+        }
+
+        if i == start {
+            return Err(PyValueError::new_err(format!(
+                "Unexpected character `{}` in path",
+                c
+            )));
+        }
+
+        let text = chars[start..i].iter().collect::<String>();
+        let value = text
+            .parse::<f64>()
+            .map_err(|_| PyValueError::new_err(format!("Invalid number `{}` in path", text)))?;
+
+        tokens.push(Token::Number(value));
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Default)]
+struct PathBuilder {
+    subpaths: Vec<Subpath>,
+    current: Vec<Point>,
+    position: Point,
+    start: Point,
+}
+
+impl PathBuilder {
+    fn move_to(&mut self, point: Point) {
+        self.finish(false);
+        self.current.push(point);
+        self.position = point;
+        self.start = point;
+    }
+
+    fn line_to(&mut self, point: Point) {
+        if self.current.is_empty() {
+            self.current.push(self.position);
+        }
+
+        self.current.push(point);
+        self.position = point;
+    }
+
+    fn cubic_to(&mut self, c1: Point, c2: Point, point: Point) {
+        let p0 = self.position;
+        let steps = curve_steps(&[p0, c1, c2, point]);
+
+        for i in 1..=steps {
+            let t = i as f64 / steps as f64;
+            let mt = 1. - t;
+            let (a, b, c, d) = (mt * mt * mt, 3. * mt * mt * t, 3. * mt * t * t, t * t * t);
+
+            self.line_to((
+                a * p0.0 + b * c1.0 + c * c2.0 + d * point.0,
+                a * p0.1 + b * c1.1 + c * c2.1 + d * point.1,
+            ));
+        }
+    }
+
+    fn quad_to(&mut self, c1: Point, point: Point) {
+        let p0 = self.position;
+        let steps = curve_steps(&[p0, c1, point]);
+
+        for i in 1..=steps {
+            let t = i as f64 / steps as f64;
+            let mt = 1. - t;
+            let (a, b, c) = (mt * mt, 2. * mt * t, t * t);
+
+            self.line_to((
+                a * p0.0 + b * c1.0 + c * point.0,
+                a * p0.1 + b * c1.1 + c * point.1,
+            ));
+        }
+    }
+
+    fn close(&mut self) {
+        self.finish(true);
+        self.position = self.start;
+    }
+
+    fn finish(&mut self, closed: bool) {
+        if !self.current.is_empty() {
+            self.subpaths.push(Subpath {
+                points: mem::take(&mut self.current),
+                closed,
+            });
+        }
+    }
+}
+
+/// Returns the amount of line segments used to approximate a curve with the given control points.
+fn curve_steps(points: &[Point]) -> usize {
+    let length = points
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+        .sum::<f64>();
+
+    ((length / 2.).ceil() as usize).clamp(1, 512)
+}
+
+fn number(tokens: &mut Peekable<IntoIter<Token>>) -> PyResult<f64> {
+    match tokens.next() {
+        Some(Token::Number(value)) => Ok(value),
+        _ => Err(PyValueError::new_err("Expected a number in path")),
+    }
+}
+
+fn point(tokens: &mut Peekable<IntoIter<Token>>, origin: Point) -> PyResult<Point> {
+    Ok((number(tokens)? + origin.0, number(tokens)? + origin.1))
+}
+
+/// Parses a simplified SVG path string into its subpaths, flattening any curves into line segments.
+///
+/// The supported commands are moveto (`M`), lineto (`L`, `H`, `V`), cubic and quadratic curveto (`C`, `Q`),
+/// and closepath (`Z`), in both their absolute (uppercase) and relative (lowercase) forms.
+pub fn parse(path: &str) -> PyResult<Vec<Subpath>> {
+    let mut tokens = tokenize(path)?.into_iter().peekable();
+    let mut builder = PathBuilder::default();
+    let mut command = None;
+
+    while let Some(&token) = tokens.peek() {
+        if let Token::Command(c) = token {
+            command = Some(c);
+            tokens.next();
+        }
+
+        let c = command.ok_or_else(|| PyValueError::new_err("Expected a command in path"))?;
+        let origin = if c.is_ascii_lowercase() {
+            builder.position
+        } else {
+            (0., 0.)
+        };
+
+        match c.to_ascii_uppercase() {
+            'M' => {
+                builder.move_to(point(&mut tokens, origin)?);
+
+                // Any coordinates following a moveto are treated as implicit lineto commands.
+                command = Some(if c == 'm' { 'l' } else { 'L' });
+            }
+            'L' => builder.line_to(point(&mut tokens, origin)?),
+            'H' => {
+                let x = number(&mut tokens)? + origin.0;
+                builder.line_to((x, builder.position.1));
+            }
+            'V' => {
+                let y = number(&mut tokens)? + origin.1;
+                builder.line_to((builder.position.0, y));
+            }
+            'C' => {
+                let c1 = point(&mut tokens, origin)?;
+                let c2 = point(&mut tokens, origin)?;
+                let end = point(&mut tokens, origin)?;
+
+                builder.cubic_to(c1, c2, end);
+            }
+            'Q' => {
+                let c1 = point(&mut tokens, origin)?;
+                let end = point(&mut tokens, origin)?;
+
+                builder.quad_to(c1, end);
+            }
+            'Z' => {
+                builder.close();
+                command = None;
+            }
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unsupported path command `{}`",
+                    c
+                )))
+            }
+        }
+    }
+
+    builder.finish(false);
+
+    Ok(builder.subpaths)
+}
+
+/// Returns the edges of the closed polygon formed by the given points.
+fn polygon_edges(points: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    (0..points.len()).map(move |i| (points[i], points[(i + 1) % points.len()]))
+}
+
+/// Marks the pixels whose centers lie inside of the shape formed by the given edges, using the nonzero winding rule.
+fn rasterize(edges: &[(Point, Point)], width: usize, height: usize, coverage: &mut [bool]) {
+    let (min_y, max_y) = edges
+        .iter()
+        .flat_map(|&(a, b)| [a.1, b.1])
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), y| {
+            (lo.min(y), hi.max(y))
+        });
+
+    if min_y > max_y {
+        return;
+    }
+
+    let y0 = (min_y - 0.5).ceil().max(0.) as usize;
+    let y1 = ((max_y - 0.5).ceil().max(0.) as usize).min(height);
+    let mut crossings: Vec<(f64, i32)> = Vec::new();
+
+    for y in y0..y1 {
+        let sy = y as f64 + 0.5;
+
+        crossings.clear();
+        for &(a, b) in edges {
+            let winding = if a.1 <= sy && b.1 > sy {
+                1
+            } else if b.1 <= sy && a.1 > sy {
+                -1
+            } else {
+                continue;
+            };
+
+            crossings.push((a.0 + (sy - a.1) / (b.1 - a.1) * (b.0 - a.0), winding));
+        }
+
+        crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let mut winding = 0;
+        for pair in crossings.windows(2) {
+            winding += pair[0].1;
+
+            if winding != 0 {
+                let start = (pair[0].0 - 0.5).ceil().max(0.) as usize;
+                let end = ((pair[1].0 - 0.5).ceil().max(0.) as usize).min(width);
+
+                for x in start..end {
+                    coverage[y * width + x] = true;
+                }
+            }
+        }
+    }
+}
+
+/// The shape drawn at the ends of open subpaths when stroking.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+/// The shape drawn at the corners between segments when stroking.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+pub fn get_line_cap(cap: &str) -> PyResult<LineCap> {
+    match cap {
+        "butt" => Ok(LineCap::Butt),
+        "round" => Ok(LineCap::Round),
+        "square" => Ok(LineCap::Square),
+        _ => Err(PyValueError::new_err(
+            "cap provided is not valid, it must be one of `butt`, `round`, or `square`".to_string(),
+        )),
+    }
+}
+
+pub fn get_line_join(join: &str) -> PyResult<LineJoin> {
+    match join {
+        "miter" => Ok(LineJoin::Miter),
+        "round" => Ok(LineJoin::Round),
+        "bevel" => Ok(LineJoin::Bevel),
+        _ => Err(PyValueError::new_err(
+            "join provided is not valid, it must be one of `miter`, `round`, or `bevel`"
+                .to_string(),
+        )),
+    }
+}
+
+/// The maximum ratio of a miter's length to the stroke thickness before it is drawn as a bevel instead,
+/// matching the SVG default.
+const MITER_LIMIT: f64 = 4.;
+
+fn circle(center: Point, radius: f64) -> Vec<Point> {
+    let steps = ((radius * 4.).ceil() as usize).clamp(8, 128);
+
+    (0..steps)
+        .map(|i| {
+            let angle = i as f64 / steps as f64 * std::f64::consts::TAU;
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Returns the polygon filling the corner at `point` between a segment with direction `d1` and one with direction `d2`.
+fn join_polygon(
+    point: Point,
+    d1: Point,
+    d2: Point,
+    half: f64,
+    join: LineJoin,
+) -> Option<Vec<Point>> {
+    let cross = d1.0 * d2.1 - d1.1 * d2.0;
+    let dot = d1.0 * d2.0 + d1.1 * d2.1;
+
+    if cross == 0. && dot > 0. {
+        return None;
+    }
+
+    if join == LineJoin::Round {
+        return Some(circle(point, half));
+    }
+
+    // The gap left between the two segments is on the side opposite to the direction of the turn.
+    let side = if cross > 0. { -half } else { half };
+    let n1 = (-d1.1 * side, d1.0 * side);
+    let n2 = (-d2.1 * side, d2.0 * side);
+    let a = (point.0 + n1.0, point.1 + n1.1);
+    let b = (point.0 + n2.0, point.1 + n2.1);
+
+    if join == LineJoin::Miter {
+        // The cosine of half of the turning angle, which is the ratio of the thickness to the miter length.
+        let cos = ((1. + dot) / 2.).sqrt();
+
+        if cos > 0. && 1. / cos <= MITER_LIMIT {
+            let bisector = (n1.0 + n2.0, n1.1 + n2.1);
+            let scale = half / cos / bisector.0.hypot(bisector.1);
+
+            return Some(vec![
+                point,
+                a,
+                (point.0 + bisector.0 * scale, point.1 + bisector.1 * scale),
+                b,
+            ]);
+        }
+    }
+
+    Some(vec![point, a, b])
+}
+
+/// Returns the polygons that make up the stroke of the given subpath.
+fn stroke_polygons(
+    subpath: &Subpath,
+    thickness: f64,
+    cap: LineCap,
+    join: LineJoin,
+) -> Vec<Vec<Point>> {
+    let half = thickness / 2.;
+    let closed = subpath.closed;
+
+    let mut points = subpath.points.clone();
+    points.dedup();
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    let count = points.len();
+    if count == 0 {
+        return Vec::new();
+    }
+
+    // A lone point is only visible as a dot if it has a round or square cap.
+    if count == 1 {
+        let (x, y) = points[0];
+
+        return match cap {
+            LineCap::Butt => Vec::new(),
+            LineCap::Round => vec![circle((x, y), half)],
+            LineCap::Square => vec![vec![
+                (x - half, y - half),
+                (x + half, y - half),
+                (x + half, y + half),
+                (x - half, y + half),
+            ]],
+        };
+    }
+
+    let direction = |i: usize| {
+        let (a, b) = (points[i], points[(i + 1) % count]);
+        let length = (b.0 - a.0).hypot(b.1 - a.1);
+
+        ((b.0 - a.0) / length, (b.1 - a.1) / length)
+    };
+
+    let segments = if closed { count } else { count - 1 };
+    let mut polygons = Vec::new();
+
+    for i in 0..segments {
+        let (mut a, mut b) = (points[i], points[(i + 1) % count]);
+        let d = direction(i);
+
+        if !closed && cap == LineCap::Square {
+            if i == 0 {
+                a = (a.0 - d.0 * half, a.1 - d.1 * half);
+            }
+
+            if i == segments - 1 {
+                b = (b.0 + d.0 * half, b.1 + d.1 * half);
+            }
+        }
+
+        let (nx, ny) = (-d.1 * half, d.0 * half);
+
+        polygons.push(vec![
+            (a.0 + nx, a.1 + ny),
+            (b.0 + nx, b.1 + ny),
+            (b.0 - nx, b.1 - ny),
+            (a.0 - nx, a.1 - ny),
+        ]);
+    }
+
+    let corners = if closed { 0..count } else { 1..count - 1 };
+    for i in corners {
+        let incoming = direction((i + count - 1) % count);
+
+        polygons.extend(join_polygon(points[i], incoming, direction(i), half, join));
+    }
+
+    if !closed && cap == LineCap::Round {
+        polygons.push(circle(points[0], half));
+        polygons.push(circle(points[count - 1], half));
+    }
+
+    polygons
+}
+
+/// Overlays the given color onto each covered pixel, following the overlay mode of the image.
+fn paint(image: &mut RilImage, coverage: &[bool], color: Dynamic) {
+    let width = image.width() as usize;
+
+    for (i, _) in coverage.iter().enumerate().filter(|(_, &covered)| covered) {
+        image.overlay_pixel((i % width) as u32, (i / width) as u32, color);
+    }
+}
+
+/// Fills the interior of the given subpaths with the given color. Open subpaths are implicitly closed.
+pub fn fill(image: &mut RilImage, subpaths: &[Subpath], color: Dynamic) {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let mut coverage = vec![false; width * height];
+
+    let edges = subpaths
+        .iter()
+        .flat_map(|subpath| polygon_edges(&subpath.points))
+        .collect::<Vec<_>>();
+
+    rasterize(&edges, width, height, &mut coverage);
+    paint(image, &coverage, color);
+}
+
+/// Strokes the outline of the given subpaths with the given color and thickness.
+pub fn stroke(
+    image: &mut RilImage,
+    subpaths: &[Subpath],
+    color: Dynamic,
+    thickness: f64,
+    cap: LineCap,
+    join: LineJoin,
+) {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let mut coverage = vec![false; width * height];
+
+    for polygon in subpaths
+        .iter()
+        .flat_map(|subpath| stroke_polygons(subpath, thickness, cap, join))
+    {
+        let edges = polygon_edges(&polygon).collect::<Vec<_>>();
+        rasterize(&edges, width, height, &mut coverage);
+    }
+
+    paint(image, &coverage, color);
+}
@@ -10,3 +10,30 @@ pub fn cast_pixel_to_pyobject(py: Python<'_>, pixel: Dynamic) -> PyObject {
         Dynamic::Rgba(v) => Rgba::from(v).into_py(py),
     }
 }
+
+pub fn cast_pixel_to_rgba(pixel: Dynamic) -> ril::Rgba {
+    match pixel {
+        Dynamic::BitPixel(v) => {
+            let value = if v.value() { 255 } else { 0 };
+            ril::Rgba {
+                r: value,
+                g: value,
+                b: value,
+                a: 255,
+            }
+        }
+        Dynamic::L(v) => ril::Rgba {
+            r: v.value(),
+            g: v.value(),
+            b: v.value(),
+            a: 255,
+        },
+        Dynamic::Rgb(v) => ril::Rgba {
+            r: v.r,
+            g: v.g,
+            b: v.b,
+            a: 255,
+        },
+        Dynamic::Rgba(v) => v,
+    }
+}
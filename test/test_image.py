@@ -1,4 +1,6 @@
-from ril import Image, ImageSequence, Pixel, Rgba
+import pytest
+
+from ril import Image, ImageSequence, Pixel, ResizeAlgorithm, Rgb, Rgba
 
 PIXELS = [
     Rgba(255, 0, 0, 255),
@@ -31,3 +33,211 @@ def test_gif_decode(fetch_file) -> None:
     for i, frame in enumerate(ImageSequence.from_bytes(fetch_file('sample_rgba.gif'))):
         assert frame.dimensions == (256, 256)
         assert frame.image.get_pixel(0, 0) == PIXELS[i]
+
+def test_gradient_mask() -> None:
+    mask = Image.gradient_mask(256, 1, 'horizontal')
+
+    assert mask.mode == 'L'
+    assert mask.get_pixel(0, 0).value == 0
+    assert mask.get_pixel(255, 0).value == 255
+
+def test_feather() -> None:
+    image = Image.new(32, 32, Pixel.from_rgba(255, 255, 255, 255))
+    image.feather(4)
+
+    assert image.get_pixel(0, 0).a < 64
+    assert image.get_pixel(16, 16).a == 255
+
+def test_inpaint() -> None:
+    image = Image.new(8, 8, Pixel.from_rgb(0, 0, 255))
+    image.set_pixel(4, 4, Pixel.from_rgb(255, 0, 0))
+
+    mask = Image.new(8, 8, Pixel.from_bitpixel(False))
+    mask.set_pixel(4, 4, Pixel.from_bitpixel(True))
+
+    image.inpaint(mask)
+
+    assert image.get_pixel(4, 4) == Rgb(0, 0, 255)
+
+def test_draw_path() -> None:
+    image = Image.new(100, 100, Pixel.from_rgb(0, 0, 0))
+    image.draw_path('M10 10 L90 10 L90 90 Z', fill=Pixel.from_rgb(255, 0, 0))
+
+    assert image.get_pixel(80, 20) == Rgb(255, 0, 0)
+    assert image.get_pixel(20, 80) == Rgb(0, 0, 0)
+
+def test_draw_path_caps_and_joins() -> None:
+    white = Pixel.from_rgb(255, 255, 255)
+
+    butt = Image.new(40, 40, Pixel.from_rgb(0, 0, 0))
+    butt.draw_path('M10 20 L30 20', stroke=white, thickness=6)
+
+    square = Image.new(40, 40, Pixel.from_rgb(0, 0, 0))
+    square.draw_path('M10 20 L30 20', stroke=white, thickness=6, cap='square')
+
+    assert butt.get_pixel(8, 20) == Rgb(0, 0, 0)
+    assert square.get_pixel(8, 20) == Rgb(255, 255, 255)
+
+    corner = Image.new(40, 40, Pixel.from_rgb(0, 0, 0))
+    corner.draw_path('M10 10 L30 10 L30 30', stroke=white, thickness=6, join='miter')
+
+    assert corner.get_pixel(32, 8) == Rgb(255, 255, 255)
+
+def test_paste_tiled() -> None:
+    image = Image.new(10, 10, Pixel.from_rgb(0, 0, 0))
+    pattern = Image.new(3, 3, Pixel.from_rgb(255, 255, 255))
+    image.paste_tiled(2, 2, 8, 7, pattern)
+
+    assert image.get_pixel(2, 2) == Rgb(255, 255, 255)
+    assert image.get_pixel(7, 6) == Rgb(255, 255, 255)
+    assert image.get_pixel(8, 6) == Rgb(0, 0, 0)
+    assert image.get_pixel(7, 7) == Rgb(0, 0, 0)
+
+def test_chroma_key() -> None:
+    image = Image.new(2, 1, Pixel.from_rgb(0, 255, 0))
+    image.set_pixel(1, 0, Pixel.from_rgb(100, 200, 100))
+    image.chroma_key(Pixel.from_rgb(0, 255, 0), 32, spill_suppression=1.0)
+
+    assert image.mode == 'RGBA'
+    assert image.get_pixel(0, 0).a == 0
+    assert image.get_pixel(1, 0) == Rgba(100, 100, 100, 255)
+
+def test_chroma_key_gray_and_softness() -> None:
+    image = Image.new(2, 1, Pixel.from_rgb(128, 128, 128))
+    image.set_pixel(1, 0, Pixel.from_rgb(200, 100, 100))
+    image.chroma_key(Pixel.from_rgb(128, 128, 128), 8, spill_suppression=1.0)
+
+    assert image.get_pixel(0, 0).a == 0
+    assert image.get_pixel(1, 0) == Rgba(200, 100, 100, 255)
+
+    image = Image.new(1, 1, Pixel.from_rgb(0, 0, 0))
+    image.chroma_key(Pixel.from_rgb(30, 30, 30), 10, softness=40)
+
+    assert image.get_pixel(0, 0).a == 128
+
+def test_mipmaps() -> None:
+    image = Image.new(8, 2, Pixel.from_rgb(255, 255, 255))
+
+    assert [level.dimensions for level in image.mipmaps()] == [(8, 2), (4, 1), (2, 1), (1, 1)]
+
+def test_resize_premultiplied_alpha() -> None:
+    image = Image.from_pixels(2, [Pixel.from_rgba(255, 255, 255, 255), Pixel.from_rgba(0, 0, 0, 0)])
+    image.resize(1, 1, ResizeAlgorithm.Box)
+
+    pixel = image.get_pixel(0, 0)
+    assert (pixel.r, pixel.g, pixel.b) == (255, 255, 255)
+
+def test_on_checkerboard() -> None:
+    image = Image.new(16, 16, Pixel.from_rgba(0, 0, 0, 0)).on_checkerboard(8)
+
+    assert image.mode == 'RGB'
+    assert image.get_pixel(0, 0) == Rgb(255, 255, 255)
+    assert image.get_pixel(8, 0) == Rgb(204, 204, 204)
+
+def test_find_contours() -> None:
+    mask = Image.new(5, 5, Pixel.from_bitpixel(False))
+    for x in range(1, 4):
+        for y in range(1, 4):
+            mask.set_pixel(x, y, Pixel.from_bitpixel(True))
+
+    assert mask.find_contours() == ([[(1, 1), (2, 1), (3, 1), (3, 2), (3, 3), (2, 3), (1, 3), (1, 2)]], [])
+
+    mask.set_pixel(2, 2, Pixel.from_bitpixel(False))
+
+    contours, holes = mask.find_contours(holes=True)
+
+    assert len(contours) == 1
+    assert holes == [(0, [(1, 2), (2, 3), (3, 2), (2, 1)])]
+
+def test_connected_components() -> None:
+    mask = Image.new(3, 3, Pixel.from_bitpixel(False))
+    mask.set_pixel(0, 0, Pixel.from_bitpixel(True))
+    mask.set_pixel(1, 1, Pixel.from_bitpixel(True))
+    mask.set_pixel(2, 1, Pixel.from_bitpixel(True))
+
+    labels, count, components = mask.connected_components()
+
+    assert count == 2
+    assert labels == [[1, 0, 0], [0, 2, 2], [0, 0, 0]]
+    assert components == [(0, 0, 1, 1, 1), (1, 1, 3, 2, 2)]
+    assert mask.connected_components(connectivity=8)[1] == 1
+
+def test_distance_transform() -> None:
+    mask = Image.new(9, 1, Pixel.from_bitpixel(True))
+    mask.set_pixel(0, 0, Pixel.from_bitpixel(False))
+
+    distances = mask.distance_transform()
+
+    assert distances.mode == 'L'
+    assert [distances.get_pixel(x, 0).value for x in range(4)] == [0, 1, 2, 3]
+
+def test_match_template() -> None:
+    image = Image.new(16, 16, Pixel.from_rgb(0, 0, 0))
+    image.set_pixel(9, 5, Pixel.from_rgb(255, 255, 255))
+    image.set_pixel(10, 6, Pixel.from_rgb(255, 255, 255))
+
+    template = Image.new(3, 3, Pixel.from_rgb(0, 0, 0))
+    template.set_pixel(1, 1, Pixel.from_rgb(255, 255, 255))
+    template.set_pixel(2, 2, Pixel.from_rgb(255, 255, 255))
+
+    x, y, score = image.match_template(template)
+
+    assert (x, y) == (8, 4)
+    assert score > 0.99
+
+    with pytest.raises(ValueError):
+        template.match_template(image)
+
+def test_match_template_flat_regions() -> None:
+    image = Image.new(64, 64, Pixel.from_rgb(203, 203, 203))
+
+    assert image.match_template(Image.new(32, 32, Pixel.from_rgb(17, 17, 17)))[2] == 0.0
+
+    image.paste(20, 24, Image.new(32, 32, Pixel.from_rgb(17, 17, 17)))
+
+    assert image.match_template(Image.new(32, 32, Pixel.from_rgb(17, 17, 17))) == (20, 24, 1.0)
+
+    template = Image.new(32, 32, Pixel.from_rgb(0, 0, 0))
+    template.set_pixel(16, 16, Pixel.from_rgb(255, 255, 255))
+
+    assert image.match_template(template)[2] == 0.0
+
+def test_glow() -> None:
+    image = Image.new(9, 9, Pixel.from_rgb(0, 0, 0))
+    image.set_pixel(4, 4, Pixel.from_rgb(255, 255, 255))
+    image.glow(200, 10.0, 1)
+
+    assert image.get_pixel(4, 4) == Rgb(255, 255, 255)
+    assert image.get_pixel(5, 4).r > 0
+    assert image.get_pixel(0, 0) == Rgb(0, 0, 0)
+
+def test_draw_path_blends_translucent_fill() -> None:
+    image = Image.new(10, 10, Pixel.from_rgba(0, 0, 255, 255))
+    image.draw_path('M0 0 L10 0 L10 10 L0 10 Z', fill=Pixel.from_rgba(255, 0, 0, 128))
+
+    pixel = image.get_pixel(5, 5)
+    assert 100 < pixel.r < 160
+    assert 100 < pixel.b < 160
+    assert pixel.a == 255
+
+def test_paste_tiled_mask_dimensions() -> None:
+    image = Image.new(10, 10, Pixel.from_rgb(0, 0, 0))
+    pattern = Image.new(3, 3, Pixel.from_rgb(255, 255, 255))
+    mask = Image.new(2, 2, Pixel.from_bitpixel(True))
+
+    with pytest.raises(ValueError):
+        image.paste_tiled(0, 0, 10, 10, pattern, mask)
+
+def test_resize_keeps_uniform_translucent_colors() -> None:
+    for width, height in [(2, 2), (7, 7)]:
+        image = Image.new(4, 4, Pixel.from_rgba(100, 0, 0, 2))
+        image.resize(width, height, ResizeAlgorithm.Bilinear)
+
+        assert all(pixel == Rgba(100, 0, 0, 2) for row in image.pixels() for pixel in row)
+
+def test_resize_nearest_keeps_translucent_pixels() -> None:
+    image = Image.from_pixels(2, [Pixel.from_rgba(100, 0, 0, 2), Pixel.from_rgba(10, 20, 30, 0)])
+    image.resize(4, 2, ResizeAlgorithm.Nearest)
+
+    assert image.get_pixel(0, 0) == Rgba(100, 0, 0, 2)
+    assert image.get_pixel(3, 1) == Rgba(10, 20, 30, 0)